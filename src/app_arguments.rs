@@ -6,6 +6,7 @@ pub struct AppArguments {
     pub templates_path: Option<String>,
     pub definitions_path: Option<String>,
     pub results_root_path: Option<String>,
+    pub env_vars: Vec<(String, String)>,
 }
 
 pub struct ArgumentsParsingResult {
@@ -14,6 +15,12 @@ pub struct ArgumentsParsingResult {
     pub is_error: bool,
 }
 
+enum ValueKind {
+    PlainString,
+    ExistingDir,
+    WritableDir,
+}
+
 struct ArgumentDefinition {
     name: &'static str,
     syntax: &'static str,
@@ -21,6 +28,8 @@ struct ArgumentDefinition {
     description: &'static str,
     number_of_args: usize,
     is_required: bool,
+    is_repeatable: bool,
+    value_kind: ValueKind,
 }
 
 const SUPPORTED_ARGS: &[ArgumentDefinition] = &[
@@ -31,6 +40,8 @@ const SUPPORTED_ARGS: &[ArgumentDefinition] = &[
         description: "Show this help",
         number_of_args: 0,
         is_required: false,
+        is_repeatable: false,
+        value_kind: ValueKind::PlainString,
     },
     ArgumentDefinition {
         name: "--version",
@@ -39,6 +50,8 @@ const SUPPORTED_ARGS: &[ArgumentDefinition] = &[
         description: "Show the application version",
         number_of_args: 0,
         is_required: false,
+        is_repeatable: false,
+        value_kind: ValueKind::PlainString,
     },
     ArgumentDefinition {
         name: "--templates-path",
@@ -47,6 +60,8 @@ const SUPPORTED_ARGS: &[ArgumentDefinition] = &[
         description: "Set path to the templates directory",
         number_of_args: 1,
         is_required: true,
+        is_repeatable: false,
+        value_kind: ValueKind::ExistingDir,
     },
     ArgumentDefinition {
         name: "--definitions-path",
@@ -55,6 +70,8 @@ const SUPPORTED_ARGS: &[ArgumentDefinition] = &[
         description: "Set path to the directory with definitions",
         number_of_args: 1,
         is_required: true,
+        is_repeatable: false,
+        value_kind: ValueKind::ExistingDir,
     },
     ArgumentDefinition {
         name: "--results-root-path",
@@ -63,6 +80,38 @@ const SUPPORTED_ARGS: &[ArgumentDefinition] = &[
         description: "Set root directory for results, default is the current directory",
         number_of_args: 1,
         is_required: false,
+        is_repeatable: false,
+        value_kind: ValueKind::WritableDir,
+    },
+    ArgumentDefinition {
+        name: "--completions",
+        syntax: "--completions <shell>",
+        shorthand: Some("-c"),
+        description: "Print a completion script for the given shell (bash, zsh, fish)",
+        number_of_args: 1,
+        is_required: false,
+        is_repeatable: false,
+        value_kind: ValueKind::PlainString,
+    },
+    ArgumentDefinition {
+        name: "--config",
+        syntax: "--config <path>",
+        shorthand: None,
+        description: "Read argument values from a 'key = value' config file",
+        number_of_args: 1,
+        is_required: false,
+        is_repeatable: false,
+        value_kind: ValueKind::PlainString,
+    },
+    ArgumentDefinition {
+        name: "--env",
+        syntax: "--env <name> <value>",
+        shorthand: Some("-e"),
+        description: "Set an environment variable for the generation, can be repeated",
+        number_of_args: 2,
+        is_required: false,
+        is_repeatable: true,
+        value_kind: ValueKind::PlainString,
     },
 ];
 
@@ -93,14 +142,64 @@ impl ArgumentsParsingResult {
 }
 
 pub fn get_app_arguments() -> ArgumentsParsingResult {
-    let args: Vec<String> = std::env::args().collect();
+    let raw_args: Vec<String> = std::env::args().collect();
+
+    // Normalize the raw arguments into the space-separated form the rest of the
+    // loop expects: split `--name=value` into two tokens. Bundled zero-arg
+    // shorthands like `-hv` are expanded inside the parse loop instead, so that
+    // only tokens in flag position are expanded and option values that start
+    // with a single `-` are left untouched. The `--name=value` split applies to
+    // every token, option values included.
+    let mut args: Vec<String> = Vec::with_capacity(raw_args.len());
+    if let Some(program) = raw_args.first() {
+        args.push(program.clone());
+    }
+    for arg in raw_args.iter().skip(1) {
+        if arg.starts_with("--") {
+            if let Some(equals_index) = arg.find('=') {
+                args.push(arg[..equals_index].to_string());
+                args.push(arg[equals_index + 1..].to_string());
+            } else {
+                args.push(arg.clone());
+            }
+        } else {
+            args.push(arg.clone());
+        }
+    }
 
     let mut templates_path = None;
     let mut definitions_path = None;
     let mut results_root_path = None;
+    let mut config_path = None;
+    let mut env_vars = Vec::new();
 
     let mut i: usize = 1;
     while i < args.len() {
+        // Expand a bundled zero-arg shorthand like `-hv` into `-h -v`, but only
+        // here in flag position: option values are skipped over by the
+        // `number_of_args` stride below and never reach this point.
+        if args[i].starts_with("-") && !args[i].starts_with("--") && args[i].len() > 2 {
+            let token = args[i].clone();
+            let mut expanded = Vec::new();
+            for shorthand_char in token[1..].chars() {
+                let shorthand = format!("-{}", shorthand_char);
+                let found_arg = SUPPORTED_ARGS
+                    .iter()
+                    .find(|supported_arg| supported_arg.shorthand == Some(shorthand.as_str()));
+                match found_arg {
+                    Some(found_arg) if found_arg.number_of_args == 0 => expanded.push(shorthand),
+                    _ => {
+                        return ArgumentsParsingResult::error(format!(
+                            "Unsupported argument: {}\nUse --help to see the list of supported arguments",
+                            token
+                        ));
+                    }
+                }
+            }
+            args.splice(i..=i, expanded);
+            continue;
+        }
+
         let arg = &args[i];
 
         let found_arg = if arg.starts_with("--") {
@@ -116,10 +215,14 @@ pub fn get_app_arguments() -> ArgumentsParsingResult {
         };
 
         let Some(found_arg) = found_arg else {
-            return ArgumentsParsingResult::error(format!(
+            let mut message = format!(
                 "Unsupported argument: {}\nUse --help to see the list of supported arguments",
                 arg
-            ));
+            );
+            if let Some(suggestion) = suggest_argument(arg) {
+                message.push_str(&format!("\nDid you mean '{}'?", suggestion));
+            }
+            return ArgumentsParsingResult::error(message);
         };
 
         if found_arg.number_of_args > 0 && i + found_arg.number_of_args >= args.len() {
@@ -132,6 +235,15 @@ pub fn get_app_arguments() -> ArgumentsParsingResult {
         if arg == "--help" || arg == "-h" {
             return ArgumentsParsingResult::message(get_help_text());
         }
+        if arg == "--completions" || arg == "-c" {
+            return match generate_completion_script(&args[i + 1]) {
+                Some(script) => ArgumentsParsingResult::message(script),
+                None => ArgumentsParsingResult::error(format!(
+                    "Unsupported shell for completions: {}\nSupported shells are: bash, zsh, fish",
+                    args[i + 1]
+                )),
+            };
+        }
         if arg == "--version" || arg == "-v" {
             return ArgumentsParsingResult::message(env!("CARGO_PKG_VERSION").to_string());
         } else if arg == "--templates-path" || arg == "-t" {
@@ -140,24 +252,68 @@ pub fn get_app_arguments() -> ArgumentsParsingResult {
             definitions_path = Some(args[i + 1].clone());
         } else if arg == "--results-root-path" || arg == "-r" {
             results_root_path = Some(args[i + 1].clone());
+        } else if arg == "--config" {
+            config_path = Some(args[i + 1].clone());
+        } else if found_arg.is_repeatable && found_arg.number_of_args == 2 {
+            // Accumulate repeated two-value occurrences into a
+            // `Vec<(String, String)>` instead of overwriting, e.g. `--env`.
+            // Only two-value repeatable args are collected; there is no
+            // collection for one-value repeatable args, and `SUPPORTED_ARGS`
+            // defines none.
+            env_vars.push((args[i + 1].clone(), args[i + 2].clone()));
         }
 
         i += 1 + found_arg.number_of_args;
     }
 
+    // Fill in any field not supplied on the command line from the config file,
+    // then from the environment. Precedence is CLI > config file > env var >
+    // built-in default, and the required-argument check below only fires once
+    // every layer has been consulted.
+    let config_values = match &config_path {
+        Some(path) => match parse_config_file(path) {
+            Ok(values) => values,
+            Err(message) => return ArgumentsParsingResult::error(message),
+        },
+        None => std::collections::HashMap::new(),
+    };
+
+    let config_source = config_path.as_deref();
+    let templates_path = resolve_layer(
+        templates_path,
+        &config_values,
+        config_source,
+        "--templates-path",
+        "RECODER_TEMPLATES_PATH",
+    );
+    let definitions_path = resolve_layer(
+        definitions_path,
+        &config_values,
+        config_source,
+        "--definitions-path",
+        "RECODER_DEFINITIONS_PATH",
+    );
+    let results_root_path = resolve_layer(
+        results_root_path,
+        &config_values,
+        config_source,
+        "--results-root-path",
+        "RECODER_RESULTS_ROOT_PATH",
+    );
+
     let mut missing_args = Vec::new();
     for supported_arg in SUPPORTED_ARGS {
-        if supported_arg.is_required {
-            let mut found = false;
-            for arg in &args {
-                if arg == supported_arg.name || arg == supported_arg.shorthand.unwrap_or("") {
-                    found = true;
-                    break;
-                }
-            }
-            if !found {
-                missing_args.push(supported_arg.name);
-            }
+        if !supported_arg.is_required {
+            continue;
+        }
+        let resolved = match supported_arg.name {
+            "--templates-path" => &templates_path,
+            "--definitions-path" => &definitions_path,
+            "--results-root-path" => &results_root_path,
+            _ => continue,
+        };
+        if resolved.value.is_none() {
+            missing_args.push(supported_arg.name);
         }
     }
 
@@ -168,13 +324,260 @@ pub fn get_app_arguments() -> ArgumentsParsingResult {
         ));
     }
 
+    // Now that every layer has been consulted, check that the collected paths
+    // actually point at something usable before the generation phase starts.
+    for (flag, resolved) in [
+        ("--templates-path", &templates_path),
+        ("--definitions-path", &definitions_path),
+        ("--results-root-path", &results_root_path),
+    ] {
+        let value_kind = SUPPORTED_ARGS
+            .iter()
+            .find(|supported_arg| supported_arg.name == flag)
+            .map(|supported_arg| &supported_arg.value_kind)
+            .unwrap_or(&ValueKind::PlainString);
+        if let Err(message) = validate_value(flag, resolved, value_kind) {
+            return ArgumentsParsingResult::error(message);
+        }
+    }
+
     ArgumentsParsingResult::parsed(AppArguments {
-        templates_path,
-        definitions_path,
-        results_root_path,
+        templates_path: templates_path.value,
+        definitions_path: definitions_path.value,
+        results_root_path: results_root_path.value,
+        env_vars,
     })
 }
 
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let n = b.len();
+
+    let mut prev: Vec<usize> = (0..=n).collect();
+    let mut curr: Vec<usize> = vec![0; n + 1];
+    for i in 1..=a.len() {
+        curr[0] = i;
+        for j in 1..=n {
+            let substitution_cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            curr[j] = (prev[j] + 1)
+                .min(curr[j - 1] + 1)
+                .min(prev[j - 1] + substitution_cost);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[n]
+}
+
+fn suggest_argument(unknown: &str) -> Option<String> {
+    let threshold = std::cmp::max(2, unknown.len() / 3);
+
+    let mut best: Option<(usize, &'static str)> = None;
+    for supported_arg in SUPPORTED_ARGS {
+        let mut candidates = vec![supported_arg.name];
+        if let Some(shorthand) = supported_arg.shorthand {
+            candidates.push(shorthand);
+        }
+        for candidate in candidates {
+            let distance = levenshtein_distance(unknown, candidate);
+            if best.map_or(true, |(best_distance, _)| distance < best_distance) {
+                best = Some((distance, candidate));
+            }
+        }
+    }
+
+    match best {
+        Some((distance, candidate)) if distance <= threshold => Some(candidate.to_string()),
+        _ => None,
+    }
+}
+
+fn parse_config_file(path: &str) -> Result<std::collections::HashMap<String, String>, String> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|error| format!("Failed to read config file '{}': {}", path, error))?;
+
+    let mut values = std::collections::HashMap::new();
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            return Err(format!(
+                "Invalid line in config file '{}': {}",
+                path, line
+            ));
+        };
+        values.insert(
+            key.trim().trim_start_matches("--").to_string(),
+            value.trim().to_string(),
+        );
+    }
+    Ok(values)
+}
+
+struct ResolvedValue {
+    value: Option<String>,
+    source: String,
+}
+
+fn resolve_layer(
+    cli_value: Option<String>,
+    config_values: &std::collections::HashMap<String, String>,
+    config_path: Option<&str>,
+    long_name: &str,
+    env_name: &str,
+) -> ResolvedValue {
+    if cli_value.is_some() {
+        return ResolvedValue {
+            value: cli_value,
+            source: "the command line".to_string(),
+        };
+    }
+    if let Some(value) = config_values.get(long_name.trim_start_matches("--")) {
+        return ResolvedValue {
+            value: Some(value.clone()),
+            source: match config_path {
+                Some(path) => format!("config file '{}'", path),
+                None => "the config file".to_string(),
+            },
+        };
+    }
+    match std::env::var(env_name) {
+        Ok(value) if !value.is_empty() => ResolvedValue {
+            value: Some(value),
+            source: format!("environment variable {}", env_name),
+        },
+        _ => ResolvedValue {
+            value: None,
+            source: "the built-in default".to_string(),
+        },
+    }
+}
+
+fn validate_value(flag: &str, resolved: &ResolvedValue, value_kind: &ValueKind) -> Result<(), String> {
+    let Some(path) = &resolved.value else {
+        return Ok(());
+    };
+
+    match value_kind {
+        ValueKind::PlainString => Ok(()),
+        ValueKind::ExistingDir => match std::fs::metadata(path) {
+            Ok(metadata) if metadata.is_dir() => Ok(()),
+            Ok(_) => Err(format!(
+                "The path for {} (from {}) is not a directory: {}",
+                flag, resolved.source, path
+            )),
+            Err(_) => Err(format!(
+                "The path for {} (from {}) does not exist: {}",
+                flag, resolved.source, path
+            )),
+        },
+        ValueKind::WritableDir => match std::fs::metadata(path) {
+            Ok(metadata) if !metadata.is_dir() => Err(format!(
+                "The path for {} (from {}) is not a directory: {}",
+                flag, resolved.source, path
+            )),
+            Ok(metadata) if metadata.permissions().readonly() => Err(format!(
+                "The path for {} (from {}) is not writable: {}",
+                flag, resolved.source, path
+            )),
+            Ok(_) => Ok(()),
+            Err(_) => {
+                // The directory does not exist yet; it can only be created if
+                // its parent directory already exists.
+                let parent_exists = match std::path::Path::new(path).parent() {
+                    Some(parent) => parent.as_os_str().is_empty() || parent.is_dir(),
+                    None => false,
+                };
+                if parent_exists {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "The path for {} (from {}) cannot be created, its parent directory is missing: {}",
+                        flag, resolved.source, path
+                    ))
+                }
+            }
+        },
+    }
+}
+
+fn expects_path(arg: &ArgumentDefinition) -> bool {
+    arg.name.ends_with("-path")
+}
+
+fn generate_completion_script(shell: &str) -> Option<String> {
+    match shell {
+        "bash" => Some(generate_bash_completions()),
+        "zsh" => Some(generate_zsh_completions()),
+        "fish" => Some(generate_fish_completions()),
+        _ => None,
+    }
+}
+
+fn generate_bash_completions() -> String {
+    let mut all_options = Vec::new();
+    let mut path_options = Vec::new();
+    for arg in SUPPORTED_ARGS {
+        all_options.push(arg.name);
+        if let Some(shorthand) = arg.shorthand {
+            all_options.push(shorthand);
+        }
+        if expects_path(arg) {
+            path_options.push(arg.name);
+            if let Some(shorthand) = arg.shorthand {
+                path_options.push(shorthand);
+            }
+        }
+    }
+
+    format!(
+        "_recoder() {{\n    local cur prev\n    cur=\"${{COMP_WORDS[COMP_CWORD]}}\"\n    prev=\"${{COMP_WORDS[COMP_CWORD-1]}}\"\n    case \"$prev\" in\n        {})\n            COMPREPLY=( $(compgen -d -- \"$cur\") )\n            return 0\n            ;;\n    esac\n    COMPREPLY=( $(compgen -W \"{}\" -- \"$cur\") )\n}}\ncomplete -F _recoder recoder\n",
+        path_options.join("|"),
+        all_options.join(" ")
+    )
+}
+
+fn generate_zsh_completions() -> String {
+    let mut lines = String::new();
+    for arg in SUPPORTED_ARGS {
+        let action = if expects_path(arg) { ":path:_files -/" } else { "" };
+        let description = arg.description.replace('\'', "'\\''");
+        lines.push_str(&format!("        '{}[{}]{}' \\\n", arg.name, description, action));
+        if let Some(shorthand) = arg.shorthand {
+            lines.push_str(&format!(
+                "        '{}[{}]{}' \\\n",
+                shorthand, description, action
+            ));
+        }
+    }
+
+    format!(
+        "#compdef recoder\n_recoder() {{\n    _arguments \\\n{}        '*:filename:_files'\n}}\n_recoder \"$@\"\n",
+        lines
+    )
+}
+
+fn generate_fish_completions() -> String {
+    let mut lines = String::new();
+    for arg in SUPPORTED_ARGS {
+        let mut command = format!("complete -c recoder -l {}", arg.name.trim_start_matches("--"));
+        if let Some(shorthand) = arg.shorthand {
+            command.push_str(&format!(" -s {}", shorthand.trim_start_matches('-')));
+        }
+        if expects_path(arg) {
+            command.push_str(" -r -F");
+        } else {
+            command.push_str(" -f");
+        }
+        command.push_str(&format!(" -d '{}'", arg.description.replace('\'', "\\'")));
+        lines.push_str(&command);
+        lines.push('\n');
+    }
+    lines
+}
+
 fn get_help_text() -> String {
     let mut help_text = "Supported arguments:\n".to_string();
     let mut max_syntax_len = 0;